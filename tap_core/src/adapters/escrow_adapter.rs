@@ -0,0 +1,54 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use alloy_primitives::Address;
+use async_trait::async_trait;
+
+/// `EscrowAdapter` defines a trait for adapters that track a sender's
+/// available escrow balance and reserve collateral against it on behalf of
+/// accepted receipts.
+///
+/// This trait is designed to be implemented by users of this library who
+/// want to back the [crate::receipt::state::AwaitingReserve] to
+/// [crate::receipt::state::Reserved] transition with real collateral
+/// tracking. Implementations are expected to maintain a per-sender
+/// available balance that is debited by [EscrowAdapter::reserve] and
+/// credited back by [EscrowAdapter::release] or [EscrowAdapter::rollback].
+///
+/// This trait is utilized by [crate::tap_manager], which relies on these
+/// operations to move a receipt from `AwaitingReserve` to `Reserved` only
+/// after a successful reservation, failing it back into
+/// [crate::receipt::state::Failed] when the sender's escrow is
+/// insufficient.
+#[async_trait]
+pub trait EscrowAdapter {
+    /// Defines the user-specified error type.
+    ///
+    /// This error type should implement the `Error` and `Debug` traits from the standard library.
+    /// Errors of this type are returned to the user when an operation fails.
+    type AdapterError: std::error::Error + std::fmt::Debug + Send + Sync + 'static;
+
+    /// Reserves `value` against `sender_id`'s available escrow balance.
+    ///
+    /// Implementations should debit the sender's available balance
+    /// atomically with the check for sufficient funds, returning an error
+    /// if the sender's available balance is less than `value`.
+    async fn reserve(&self, sender_id: Address, value: u128) -> Result<(), Self::AdapterError>;
+
+    /// Releases a previously reserved `value` back to `sender_id`'s
+    /// available balance.
+    ///
+    /// Called once a RAV covering the reserved receipts has been redeemed
+    /// on-chain, replenishing the sender's available balance by the
+    /// redeemed amount.
+    async fn release(&self, sender_id: Address, value: u128) -> Result<(), Self::AdapterError>;
+
+    /// Rolls back a reservation that will never be redeemed, e.g. because
+    /// the receipt was later invalidated or the aggregator rejected the RAV
+    /// request that would have covered it.
+    ///
+    /// Unlike [EscrowAdapter::release], this does not imply the value was
+    /// ever spent; it simply returns the reserved amount to the sender's
+    /// available balance.
+    async fn rollback(&self, sender_id: Address, value: u128) -> Result<(), Self::AdapterError>;
+}
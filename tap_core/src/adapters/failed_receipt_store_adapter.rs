@@ -0,0 +1,49 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use crate::{
+    receipt::{state::Failed, ReceiptWithState},
+    receipt_aggregate_voucher::ReceiptAggregateVoucher,
+};
+
+/// `FailedReceiptStore` defines a trait for adapters that persist receipts
+/// and RAV requests that were rejected, so operators can inspect and
+/// reconcile them later instead of having them silently dropped.
+///
+/// This trait is designed to be implemented by users of this library. It is
+/// called by [crate::tap_manager] whenever a receipt fails the check
+/// pipeline or an aggregator rejects a RAV request.
+#[async_trait]
+pub trait FailedReceiptStore {
+    /// Defines the user-specified error type.
+    ///
+    /// This error type should implement the `Error` and `Debug` traits from the standard library.
+    /// Errors of this type are returned to the user when an operation fails.
+    type AdapterError: std::error::Error + std::fmt::Debug + Send + Sync + 'static;
+
+    /// Persists a receipt that failed the check pipeline.
+    ///
+    /// Implementations should retain enough of the receipt (timestamp,
+    /// allocation id, sender, value, the serialized receipt itself) along
+    /// with `receipt.error` to support later debugging and reconciliation.
+    async fn store_invalid_receipt(
+        &self,
+        receipt: ReceiptWithState<Failed>,
+    ) -> Result<(), Self::AdapterError>;
+
+    /// Persists a RAV request that the aggregator rejected.
+    ///
+    /// `expected_rav` is the RAV the indexer computed and submitted;
+    /// `response` is the raw response returned by the aggregator; `reason`
+    /// is a human-readable explanation of why the request is considered
+    /// failed (e.g. a mismatch between `expected_rav` and the aggregator's
+    /// response, or a transport error).
+    async fn store_failed_rav_request(
+        &self,
+        expected_rav: ReceiptAggregateVoucher,
+        response: String,
+        reason: String,
+    ) -> Result<(), Self::AdapterError>;
+}
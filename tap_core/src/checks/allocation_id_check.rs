@@ -0,0 +1,85 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, sync::Arc};
+
+use alloy_primitives::Address;
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::{
+    checks::Check,
+    receipt::{state::Checking, ReceiptError, ReceiptWithState},
+};
+
+/// Checks that a receipt's allocation id is one the indexer currently owns
+/// and still has open for redeeming.
+pub struct AllocationIdCheck {
+    valid_allocation_ids: Arc<RwLock<HashSet<Address>>>,
+}
+
+impl AllocationIdCheck {
+    /// Creates a new allocation id check backed by `valid_allocation_ids`.
+    pub fn new(valid_allocation_ids: Arc<RwLock<HashSet<Address>>>) -> Self {
+        Self {
+            valid_allocation_ids,
+        }
+    }
+}
+
+#[async_trait]
+impl Check for AllocationIdCheck {
+    fn name(&self) -> &'static str {
+        "AllocationIdCheck"
+    }
+
+    async fn check(&self, receipt: &ReceiptWithState<Checking>) -> Result<(), ReceiptError> {
+        let allocation_id = receipt.signed_receipt().message.allocation_id;
+        check_allocation_id(allocation_id, &self.valid_allocation_ids.read().await)
+    }
+}
+
+/// The pure validation behind [AllocationIdCheck], split out so it can be
+/// tested without needing a full `ReceiptWithState`.
+fn check_allocation_id(
+    allocation_id: Address,
+    valid_allocation_ids: &HashSet<Address>,
+) -> Result<(), ReceiptError> {
+    if !valid_allocation_ids.contains(&allocation_id) {
+        return Err(ReceiptError::InvalidAllocationId { allocation_id });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::*;
+
+    use super::*;
+
+    #[fixture]
+    fn valid_allocation_ids() -> HashSet<Address> {
+        HashSet::from([
+            Address::from_str("0xabababababababababababababababababababab").unwrap(),
+        ])
+    }
+
+    #[rstest]
+    fn accepts_a_known_allocation_id(valid_allocation_ids: HashSet<Address>) {
+        let allocation_id = *valid_allocation_ids.iter().next().unwrap();
+        assert!(check_allocation_id(allocation_id, &valid_allocation_ids).is_ok());
+    }
+
+    #[rstest]
+    fn rejects_an_unknown_allocation_id(valid_allocation_ids: HashSet<Address>) {
+        let allocation_id =
+            Address::from_str("0xdeaddeaddeaddeaddeaddeaddeaddeaddeaddead").unwrap();
+        let result = check_allocation_id(allocation_id, &valid_allocation_ids);
+        assert!(matches!(
+            result,
+            Err(ReceiptError::InvalidAllocationId { allocation_id: got }) if got == allocation_id
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Checks
+//!
+//! This module replaces the old monolithic `ReceiptChecksAdapter` with a
+//! pluggable pipeline of independent [`Check`] implementors. Rather than
+//! forcing every integrator to implement a single fat trait covering every
+//! validation, each check is its own small type that can be composed,
+//! reordered, or swapped out via [`Checks`].
+//!
+//! The checks shipped by this crate ([`UniquenessCheck`], [`AllocationIdCheck`],
+//! [`ValueCheck`], [`SignatureCheck`] and [`TimestampCheck`]) cover the
+//! validations that used to live on `ReceiptChecksAdapter`, plus a
+//! timestamp window to reject stale or future-dated receipts. Integrators
+//! that need additional validation (a sender deny-list, a balance check)
+//! can implement [`Check`] themselves and register it alongside the
+//! built-ins without touching core.
+
+mod allocation_id_check;
+mod signature_check;
+mod timestamp_check;
+mod uniqueness_check;
+mod value_check;
+
+pub use allocation_id_check::AllocationIdCheck;
+pub use signature_check::SignatureCheck;
+pub use timestamp_check::TimestampCheck;
+pub use uniqueness_check::UniquenessCheck;
+pub use value_check::ValueCheck;
+
+use async_trait::async_trait;
+
+use crate::receipt::{
+    state::{AwaitingReserve, Checking, Failed},
+    ReceiptError, ReceiptWithState,
+};
+
+/// A single, independent validation that a receipt must pass before it can
+/// move out of the [`Checking`] state.
+///
+/// Implementors should be side-effect free with respect to the receipt
+/// itself; any external state they need (storage handles, allow-lists,
+/// configuration) should be owned by the implementing struct.
+#[async_trait]
+pub trait Check {
+    /// A short, stable name identifying this check, used to record which
+    /// check rejected a receipt (see [`Failed::failing_check`]).
+    fn name(&self) -> &'static str;
+
+    /// Runs this check against `receipt`, returning `Ok(())` if it passes
+    /// and the failing [`ReceiptError`] otherwise.
+    async fn check(&self, receipt: &ReceiptWithState<Checking>) -> Result<(), ReceiptError>;
+}
+
+/// An ordered collection of [`Check`] implementors that together decide
+/// whether a receipt advances to [`AwaitingReserve`] or is moved into
+/// [`Failed`].
+///
+/// Checks are run in registration order and the first failure short-circuits
+/// the remaining checks.
+pub struct Checks(Vec<Box<dyn Check + Send + Sync>>);
+
+impl Checks {
+    /// Builds a new check pipeline from an ordered list of checks.
+    pub fn new(checks: Vec<Box<dyn Check + Send + Sync>>) -> Self {
+        Self(checks)
+    }
+
+    /// Runs every registered check against `receipt` in order, driving the
+    /// typestate transition out of [`Checking`].
+    ///
+    /// Returns the receipt in the [`AwaitingReserve`] state if every check
+    /// passes, or in the [`Failed`] state carrying the first failing
+    /// [`ReceiptError`] otherwise.
+    pub async fn check(
+        &self,
+        receipt: ReceiptWithState<Checking>,
+    ) -> Result<ReceiptWithState<AwaitingReserve>, ReceiptWithState<Failed>> {
+        for check in self.0.iter() {
+            if let Err(error) = check.check(&receipt).await {
+                let reason = error.to_string();
+                let failing_check = check.name().to_string();
+                return Err(receipt.into_failed(error, failing_check, reason));
+            }
+        }
+        Ok(receipt.into_awaiting_reserve())
+    }
+}
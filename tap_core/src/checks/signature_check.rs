@@ -0,0 +1,79 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, sync::Arc};
+
+use alloy_primitives::Address;
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::{
+    checks::Check,
+    receipt::{state::Checking, ReceiptError, ReceiptWithState},
+};
+
+/// Checks that the sender recovered from a receipt's signature is one the
+/// indexer considers valid.
+pub struct SignatureCheck {
+    valid_senders: Arc<RwLock<HashSet<Address>>>,
+}
+
+impl SignatureCheck {
+    /// Creates a new signature check backed by `valid_senders`.
+    pub fn new(valid_senders: Arc<RwLock<HashSet<Address>>>) -> Self {
+        Self { valid_senders }
+    }
+}
+
+#[async_trait]
+impl Check for SignatureCheck {
+    fn name(&self) -> &'static str {
+        "SignatureCheck"
+    }
+
+    async fn check(&self, receipt: &ReceiptWithState<Checking>) -> Result<(), ReceiptError> {
+        let sender_id = receipt.signed_receipt().recover_signer()?;
+        check_sender(sender_id, &self.valid_senders.read().await)
+    }
+}
+
+/// The pure validation behind [SignatureCheck], split out so it can be
+/// tested without needing a full `ReceiptWithState`.
+fn check_sender(sender_id: Address, valid_senders: &HashSet<Address>) -> Result<(), ReceiptError> {
+    if !valid_senders.contains(&sender_id) {
+        return Err(ReceiptError::InvalidSenderId { sender_id });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::*;
+
+    use super::*;
+
+    #[fixture]
+    fn valid_senders() -> HashSet<Address> {
+        HashSet::from([
+            Address::from_str("0xabababababababababababababababababababab").unwrap(),
+        ])
+    }
+
+    #[rstest]
+    fn accepts_a_known_sender(valid_senders: HashSet<Address>) {
+        let sender_id = *valid_senders.iter().next().unwrap();
+        assert!(check_sender(sender_id, &valid_senders).is_ok());
+    }
+
+    #[rstest]
+    fn rejects_an_unknown_sender(valid_senders: HashSet<Address>) {
+        let sender_id = Address::from_str("0xdeaddeaddeaddeaddeaddeaddeaddeaddeaddead").unwrap();
+        let result = check_sender(sender_id, &valid_senders);
+        assert!(matches!(
+            result,
+            Err(ReceiptError::InvalidSenderId { sender_id: got }) if got == sender_id
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::{
+    checks::Check,
+    receipt::{state::Checking, ReceiptError, ReceiptWithState},
+};
+
+/// Checks that a receipt's `timestamp_ns` falls within an acceptable window
+/// around the current time.
+///
+/// Receipts older than `min_timestamp_ns` are rejected as stale (e.g.
+/// because they fall before the last RAV boundary and can no longer be
+/// aggregated), and receipts further in the future than `max_future_ns`
+/// allows are rejected to guard against clock skew being used to smuggle
+/// receipts past a later RAV boundary.
+///
+/// `min_timestamp_ns` is shared, mutable state (like the allow-lists backing
+/// [crate::checks::AllocationIdCheck] and [crate::checks::SignatureCheck]),
+/// so the caller can advance the boundary as RAVs are produced over the
+/// manager's lifetime without having to rebuild the check pipeline.
+pub struct TimestampCheck {
+    min_timestamp_ns: Arc<RwLock<u64>>,
+    max_future_ns: u64,
+}
+
+impl TimestampCheck {
+    /// Creates a new timestamp check.
+    ///
+    /// `min_timestamp_ns` is the oldest acceptable receipt timestamp, e.g.
+    /// the timestamp of the last RAV boundary, and is expected to be
+    /// advanced in place as later RAVs are produced. `max_future_ns` is the
+    /// maximum clock skew into the future a receipt's timestamp may have.
+    pub fn new(min_timestamp_ns: Arc<RwLock<u64>>, max_future_ns: u64) -> Self {
+        Self {
+            min_timestamp_ns,
+            max_future_ns,
+        }
+    }
+}
+
+#[async_trait]
+impl Check for TimestampCheck {
+    fn name(&self) -> &'static str {
+        "TimestampCheck"
+    }
+
+    async fn check(&self, receipt: &ReceiptWithState<Checking>) -> Result<(), ReceiptError> {
+        let timestamp_ns = receipt.signed_receipt().message.timestamp_ns;
+        let min_timestamp_ns = *self.min_timestamp_ns.read().await;
+        let now_ns = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Current system time should be greater than `UNIX_EPOCH`")
+            .as_nanos() as u64;
+
+        check_timestamp_window(timestamp_ns, min_timestamp_ns, self.max_future_ns, now_ns)
+    }
+}
+
+/// The pure validation behind [TimestampCheck], split out so the boundary
+/// conditions can be tested without needing a full `ReceiptWithState`.
+fn check_timestamp_window(
+    timestamp_ns: u64,
+    min_timestamp_ns: u64,
+    max_future_ns: u64,
+    now_ns: u64,
+) -> Result<(), ReceiptError> {
+    if timestamp_ns < min_timestamp_ns {
+        return Err(ReceiptError::TimestampTooOld {
+            received_timestamp_ns: timestamp_ns,
+            min_timestamp_ns,
+        });
+    }
+
+    if timestamp_ns > now_ns.saturating_add(max_future_ns) {
+        return Err(ReceiptError::TimestampInFuture {
+            received_timestamp_ns: timestamp_ns,
+            now_ns,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_a_timestamp_within_the_window() {
+        assert!(check_timestamp_window(1_000, 500, 100, 1_000).is_ok());
+    }
+
+    #[test]
+    fn rejects_a_timestamp_older_than_the_minimum() {
+        let result = check_timestamp_window(499, 500, 100, 1_000);
+        assert!(matches!(result, Err(ReceiptError::TimestampTooOld { .. })));
+    }
+
+    #[test]
+    fn rejects_a_timestamp_beyond_the_allowed_clock_skew() {
+        let result = check_timestamp_window(1_101, 500, 100, 1_000);
+        assert!(matches!(result, Err(ReceiptError::TimestampInFuture { .. })));
+    }
+
+    #[test]
+    fn accepts_timestamps_at_the_exact_boundaries() {
+        assert!(check_timestamp_window(500, 500, 100, 1_000).is_ok());
+        assert!(check_timestamp_window(1_100, 500, 100, 1_000).is_ok());
+    }
+}
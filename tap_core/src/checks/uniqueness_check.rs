@@ -0,0 +1,69 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::{
+    checks::Check,
+    receipt::{state::Checking, ReceiptError, ReceiptWithState},
+};
+
+/// Checks that a receipt's unique id has not already been observed.
+///
+/// The set of seen ids is shared (e.g. with the storage adapter backing the
+/// rest of the system) so that uniqueness holds across the lifetime of the
+/// indexer, not just within a single batch of checks.
+pub struct UniquenessCheck {
+    seen_receipt_ids: Arc<RwLock<HashSet<u64>>>,
+}
+
+impl UniquenessCheck {
+    /// Creates a new uniqueness check backed by `seen_receipt_ids`.
+    pub fn new(seen_receipt_ids: Arc<RwLock<HashSet<u64>>>) -> Self {
+        Self { seen_receipt_ids }
+    }
+}
+
+#[async_trait]
+impl Check for UniquenessCheck {
+    fn name(&self) -> &'static str {
+        "UniquenessCheck"
+    }
+
+    async fn check(&self, receipt: &ReceiptWithState<Checking>) -> Result<(), ReceiptError> {
+        let receipt_id = receipt.receipt_id();
+        check_uniqueness(receipt_id, &self.seen_receipt_ids.read().await)
+    }
+}
+
+/// The pure validation behind [UniquenessCheck], split out so it can be
+/// tested without needing a full `ReceiptWithState`.
+fn check_uniqueness(receipt_id: u64, seen_receipt_ids: &HashSet<u64>) -> Result<(), ReceiptError> {
+    if seen_receipt_ids.contains(&receipt_id) {
+        return Err(ReceiptError::NonUniqueReceipt);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    use super::*;
+
+    #[rstest]
+    fn accepts_an_unseen_receipt_id() {
+        let seen = HashSet::from([1_u64, 2_u64]);
+        assert!(check_uniqueness(3, &seen).is_ok());
+    }
+
+    #[rstest]
+    fn rejects_a_previously_seen_receipt_id() {
+        let seen = HashSet::from([1_u64, 2_u64]);
+        let result = check_uniqueness(2, &seen);
+        assert!(matches!(result, Err(ReceiptError::NonUniqueReceipt)));
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use crate::{
+    checks::Check,
+    receipt::{state::Checking, ReceiptError, ReceiptWithState},
+};
+
+/// Checks that a receipt's value is non-zero and within the maximum value
+/// the indexer is willing to accept for a single receipt.
+pub struct ValueCheck {
+    max_value: u128,
+}
+
+impl ValueCheck {
+    /// Creates a new value check that rejects receipts above `max_value`.
+    pub fn new(max_value: u128) -> Self {
+        Self { max_value }
+    }
+}
+
+#[async_trait]
+impl Check for ValueCheck {
+    fn name(&self) -> &'static str {
+        "ValueCheck"
+    }
+
+    async fn check(&self, receipt: &ReceiptWithState<Checking>) -> Result<(), ReceiptError> {
+        let value = receipt.signed_receipt().message.value;
+        check_value(value, self.max_value)
+    }
+}
+
+/// The pure validation behind [ValueCheck], split out so the boundary
+/// conditions can be tested without needing a full `ReceiptWithState`.
+fn check_value(value: u128, max_value: u128) -> Result<(), ReceiptError> {
+    if value == 0 || value > max_value {
+        return Err(ReceiptError::InvalidValue { value });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    use super::*;
+
+    #[rstest]
+    #[case(1, 100)]
+    #[case(100, 100)]
+    fn accepts_a_nonzero_value_within_the_maximum(#[case] value: u128, #[case] max_value: u128) {
+        assert!(check_value(value, max_value).is_ok());
+    }
+
+    #[rstest]
+    fn rejects_a_zero_value() {
+        let result = check_value(0, 100);
+        assert!(matches!(result, Err(ReceiptError::InvalidValue { value: 0 })));
+    }
+
+    #[rstest]
+    fn rejects_a_value_above_the_maximum() {
+        let result = check_value(101, 100);
+        assert!(matches!(
+            result,
+            Err(ReceiptError::InvalidValue { value: 101 })
+        ));
+    }
+}
@@ -0,0 +1,226 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Merkle Tree
+//!
+//! A commitment over the receipts aggregated into a
+//! [crate::receipt_aggregate_voucher::ReceiptAggregateVoucher], borrowing the
+//! binary Merkle tree approach used for Ethereum receipt tries. The root is
+//! embedded in the RAV so that a sender (or an on-chain verifier) can later
+//! prove that a specific receipt was, or was not, folded into a given
+//! aggregate value without the aggregator having to retain every receipt.
+//! The tree commits to each receipt's content ([Receipt]); the signature
+//! over that content is not part of the commitment.
+//!
+//! ## Leaf encoding
+//!
+//! Each leaf is `keccak256(allocation_id || value || timestamp_ns || nonce)`,
+//! where `allocation_id` is the 20 big-endian address bytes, `value` is the
+//! big-endian bytes of the `u128` value, and `timestamp_ns`/`nonce` are the
+//! big-endian bytes of their respective `u64`s. Leaves are ordered by
+//! `timestamp_ns` then `nonce` before the tree is built, so the same set of
+//! receipts always produces the same root regardless of aggregation order.
+//!
+//! ## Tree construction
+//!
+//! This is a standard binary Merkle tree: each level is built by hashing
+//! pairs of nodes with `keccak256(left || right)`, and the last node of a
+//! level with an odd number of nodes is duplicated to pair with itself. The
+//! empty tree's root is `keccak256(&[])`.
+
+use alloy_primitives::{keccak256, B256};
+
+use crate::tap_receipt::Receipt;
+
+/// An inclusion proof that a single receipt was committed to by a
+/// [MerkleTree]'s root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// The leaf hash the proof starts from.
+    pub leaf: B256,
+    /// Sibling hashes from the leaf's level up to (but not including) the
+    /// root, along with whether the sibling is the left node at that level.
+    pub siblings: Vec<(B256, Side)>,
+}
+
+/// Which side of a hashing pair a sibling occupies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// A binary Merkle tree committing to an ordered set of receipts.
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    /// Every level of the tree, from leaves (`levels[0]`) to the root
+    /// (`levels.last()`, a single node).
+    levels: Vec<Vec<B256>>,
+}
+
+impl MerkleTree {
+    /// Builds a tree committing to `receipts`.
+    ///
+    /// Receipts are sorted by `timestamp_ns` then `nonce` before hashing, so
+    /// the root is independent of the order `receipts` is given in.
+    pub fn new(receipts: &[Receipt]) -> Self {
+        let mut receipts: Vec<&Receipt> = receipts.iter().collect();
+        receipts.sort_by_key(|r| (r.timestamp_ns, r.nonce));
+
+        let leaves: Vec<B256> = receipts.iter().map(|r| leaf_hash(r)).collect();
+        Self {
+            levels: build_levels(leaves),
+        }
+    }
+
+    /// The Merkle root committing to every receipt this tree was built from.
+    pub fn root(&self) -> B256 {
+        self.levels
+            .last()
+            .and_then(|level| level.first())
+            .copied()
+            .unwrap_or_else(|| keccak256([]))
+    }
+
+    /// Builds an inclusion proof for `receipt`, or `None` if it was not part
+    /// of the set this tree was built from.
+    pub fn inclusion_proof(&self, receipt: &Receipt) -> Option<MerkleProof> {
+        let leaf = leaf_hash(receipt);
+        let mut index = self.levels.first()?.iter().position(|l| *l == leaf)?;
+
+        let mut siblings = Vec::with_capacity(self.levels.len() - 1);
+        for level in self.levels.iter().take(self.levels.len() - 1) {
+            let (sibling_index, side) = if index % 2 == 0 {
+                (index + 1, Side::Right)
+            } else {
+                (index - 1, Side::Left)
+            };
+            let sibling = level.get(sibling_index).copied().unwrap_or(level[index]);
+            siblings.push((sibling, side));
+            index /= 2;
+        }
+
+        Some(MerkleProof { leaf, siblings })
+    }
+}
+
+/// Verifies that `proof` demonstrates `receipt` was committed to by `root`.
+pub fn verify_inclusion(root: B256, proof: &MerkleProof, receipt: &Receipt) -> bool {
+    if proof.leaf != leaf_hash(receipt) {
+        return false;
+    }
+
+    let mut node = proof.leaf;
+    for (sibling, side) in &proof.siblings {
+        node = match side {
+            Side::Left => hash_pair(*sibling, node),
+            Side::Right => hash_pair(node, *sibling),
+        };
+    }
+
+    node == root
+}
+
+fn leaf_hash(receipt: &Receipt) -> B256 {
+    let mut bytes = Vec::with_capacity(20 + 16 + 8 + 8);
+    bytes.extend_from_slice(receipt.allocation_id.as_slice());
+    bytes.extend_from_slice(&receipt.value.to_be_bytes());
+    bytes.extend_from_slice(&receipt.timestamp_ns.to_be_bytes());
+    bytes.extend_from_slice(&receipt.nonce.to_be_bytes());
+    keccak256(bytes)
+}
+
+fn hash_pair(left: B256, right: B256) -> B256 {
+    let mut bytes = Vec::with_capacity(64);
+    bytes.extend_from_slice(left.as_slice());
+    bytes.extend_from_slice(right.as_slice());
+    keccak256(bytes)
+}
+
+fn build_levels(leaves: Vec<B256>) -> Vec<Vec<B256>> {
+    if leaves.is_empty() {
+        return vec![vec![keccak256([])]];
+    }
+
+    let mut levels = vec![leaves];
+    while levels.last().unwrap().len() > 1 {
+        let prev = levels.last().unwrap();
+        let mut next = Vec::with_capacity(prev.len().div_ceil(2));
+        for pair in prev.chunks(2) {
+            let left = pair[0];
+            let right = *pair.get(1).unwrap_or(&pair[0]);
+            next.push(hash_pair(left, right));
+        }
+        levels.push(next);
+    }
+    levels
+}
+
+#[cfg(test)]
+mod merkle_tree_unit_test {
+    use std::str::FromStr;
+
+    use alloy_primitives::Address;
+    use rstest::*;
+
+    use super::*;
+
+    #[fixture]
+    fn allocation_ids() -> Vec<Address> {
+        vec![
+            Address::from_str("0xabababababababababababababababababababab").unwrap(),
+            Address::from_str("0xdeaddeaddeaddeaddeaddeaddeaddeaddeaddead").unwrap(),
+            Address::from_str("0xbeefbeefbeefbeefbeefbeefbeefbeefbeefbeef").unwrap(),
+            Address::from_str("0x1234567890abcdef1234567890abcdef12345678").unwrap(),
+        ]
+    }
+
+    // Receipt::new stamps timestamp_ns with the current time, so building a
+    // few of them back-to-back is enough to exercise both even and odd leaf
+    // counts without needing a fixed clock.
+    fn receipts(allocation_ids: &[Address], count: usize) -> Vec<Receipt> {
+        (0..count)
+            .map(|i| {
+                Receipt::new(allocation_ids[i % allocation_ids.len()], 1234 + i as u128).unwrap()
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    #[case(4)]
+    #[case(5)]
+    fn test_inclusion_proof_round_trip(allocation_ids: Vec<Address>, #[case] count: usize) {
+        let receipts = receipts(&allocation_ids, count);
+        let tree = MerkleTree::new(&receipts);
+        let root = tree.root();
+
+        for receipt in &receipts {
+            let proof = tree
+                .inclusion_proof(receipt)
+                .expect("receipt was built into the tree");
+            assert!(verify_inclusion(root, &proof, receipt));
+        }
+    }
+
+    #[rstest]
+    fn test_non_member_receipt_has_no_proof(allocation_ids: Vec<Address>) {
+        let receipts = receipts(&allocation_ids, 3);
+        let tree = MerkleTree::new(&receipts);
+
+        let non_member = Receipt::new(allocation_ids[0], 999_999).unwrap();
+        assert!(tree.inclusion_proof(&non_member).is_none());
+    }
+
+    #[rstest]
+    fn test_verify_inclusion_rejects_wrong_root(allocation_ids: Vec<Address>) {
+        let receipts = receipts(&allocation_ids, 4);
+        let tree = MerkleTree::new(&receipts);
+
+        let proof = tree.inclusion_proof(&receipts[0]).unwrap();
+        let wrong_root = keccak256("not the root");
+        assert!(!verify_inclusion(wrong_root, &proof, &receipts[0]));
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::receipt::{
+    state::{AwaitingReserve, Failed, Reserved},
+    ReceiptError, ReceiptWithState,
+};
+
+impl ReceiptWithState<AwaitingReserve> {
+    /// Moves the receipt into the [`Reserved`] state once escrow has been
+    /// successfully reserved for it, see
+    /// [crate::adapters::escrow_adapter::EscrowAdapter::reserve].
+    pub fn into_reserved(self) -> ReceiptWithState<Reserved> {
+        self.perform_state_change(Reserved)
+    }
+
+    /// Moves the receipt into the [`Failed`] state, e.g. because the
+    /// sender's escrow balance was insufficient to cover it.
+    pub fn into_failed(
+        self,
+        error: ReceiptError,
+        failing_check: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> ReceiptWithState<Failed> {
+        self.perform_state_change(Failed {
+            error,
+            failing_check: Some(failing_check.into()),
+            reason: reason.into(),
+        })
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors that can cause a receipt to fail the check pipeline, or to be
+/// rejected later in its lifecycle (escrow reservation, RAV redemption).
+#[derive(Error, Debug, Clone, Serialize, Deserialize)]
+pub enum ReceiptError {
+    /// Returned by [crate::checks::UniquenessCheck] when a receipt's id has
+    /// already been observed.
+    #[error("Receipt is not unique")]
+    NonUniqueReceipt,
+
+    /// Returned by [crate::checks::AllocationIdCheck] when `allocation_id`
+    /// is not one the indexer currently has open for redeeming.
+    #[error("Invalid allocation id: {allocation_id}")]
+    InvalidAllocationId { allocation_id: Address },
+
+    /// Returned by [crate::checks::ValueCheck] when `value` is zero or above
+    /// the configured maximum.
+    #[error("Invalid value: {value}")]
+    InvalidValue { value: u128 },
+
+    /// Returned by [crate::checks::SignatureCheck] when the signer recovered
+    /// from the receipt's signature is not one the indexer considers valid.
+    #[error("Invalid sender id: {sender_id}")]
+    InvalidSenderId { sender_id: Address },
+
+    /// Returned by [crate::checks::TimestampCheck] when a receipt's
+    /// timestamp is older than the minimum accepted timestamp, e.g. because
+    /// it falls before the last RAV boundary.
+    #[error(
+        "Receipt timestamp {received_timestamp_ns} is older than the minimum accepted \
+         timestamp {min_timestamp_ns}"
+    )]
+    TimestampTooOld {
+        received_timestamp_ns: u64,
+        min_timestamp_ns: u64,
+    },
+
+    /// Returned by [crate::checks::TimestampCheck] when a receipt's
+    /// timestamp is further in the future than the allowed clock skew.
+    #[error(
+        "Receipt timestamp {received_timestamp_ns} is too far in the future (now: {now_ns})"
+    )]
+    TimestampInFuture {
+        received_timestamp_ns: u64,
+        now_ns: u64,
+    },
+
+    /// Returned by [crate::tap_manager::TapManager::reserve_receipt] when
+    /// the sender recovered from the receipt's signature does not have
+    /// enough available escrow to cover `value`.
+    #[error("Insufficient escrow for sender {sender_id}: requested {value}")]
+    InsufficientEscrow { sender_id: Address, value: u128 },
+}
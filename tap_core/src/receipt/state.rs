@@ -7,6 +7,8 @@
 //! state of a receipt.
 //! The `ReceiptState` trait represents the different states a receipt can be in.
 
+use serde::{Deserialize, Serialize};
+
 use crate::receipt::ReceiptError;
 
 /// Checking state represents a receipt that is currently being checked.
@@ -14,15 +16,30 @@ use crate::receipt::ReceiptError;
 pub struct Checking;
 
 /// Failed state represents a receipt that has failed a check or validation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Failed {
     /// A list of checks to be completed for the receipt, along with their
     /// current result
     pub error: ReceiptError,
+    /// The name of the check that rejected the receipt, e.g. `"ValueCheck"`.
+    ///
+    /// Failures raised by [crate::tap_manager::TapManager::reserve_receipt]
+    /// (insufficient escrow, or a signature that no longer recovers) record
+    /// `"EscrowAdapter::reserve"`. `None` when the failure did not originate
+    /// from a named check or step.
+    pub failing_check: Option<String>,
+    /// A human-readable explanation of why the receipt was rejected, fit
+    /// for persisting alongside the receipt for later debugging and
+    /// reconciliation.
+    pub reason: String,
 }
 
 /// AwaitingReserve state represents a receipt that has passed all checks
 /// and is awaiting escrow reservation.
+///
+/// A receipt leaves this state once [crate::adapters::escrow_adapter::EscrowAdapter::reserve]
+/// succeeds, moving to [Reserved], or fails back into [Failed] if the
+/// sender's escrow balance is insufficient.
 #[derive(Debug, Clone)]
 pub struct AwaitingReserve;
 
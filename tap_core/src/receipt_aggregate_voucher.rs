@@ -0,0 +1,132 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    merkle_tree::{MerkleProof, MerkleTree},
+    tap_manager::SignedReceipt,
+};
+
+/// A Receipt Aggregate Voucher (RAV): attests to the total value of a set
+/// of receipts for a single allocation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptAggregateVoucher {
+    pub allocation_id: Address,
+    pub timestamp_ns: u64,
+    pub value_aggregate: u128,
+    /// The root of the [MerkleTree] built over the receipts this RAV
+    /// aggregates, see [RavBuilder]. `None` for RAVs built without a
+    /// commitment.
+    pub receipts_merkle_root: Option<B256>,
+}
+
+/// Builds a [ReceiptAggregateVoucher] from a set of valid receipts, retaining
+/// the [MerkleTree] used to compute its root so that inclusion proofs can be
+/// produced for any receipt it commits to.
+pub struct RavBuilder {
+    allocation_id: Address,
+    timestamp_ns: u64,
+    value_aggregate: u128,
+    merkle_tree: MerkleTree,
+}
+
+impl RavBuilder {
+    /// Builds a RAV over `valid_receipts` for `allocation_id`.
+    ///
+    /// Receipts whose `allocation_id` does not match are not this RAV's to
+    /// aggregate (e.g. a caller batching across allocations by mistake) and
+    /// are excluded from `value_aggregate` and the Merkle commitment rather
+    /// than being silently folded in under the wrong allocation.
+    pub fn new(allocation_id: Address, valid_receipts: &[SignedReceipt]) -> Self {
+        let messages: Vec<_> = valid_receipts
+            .iter()
+            .map(|receipt| receipt.message.clone())
+            .collect();
+        let receipts = receipts_for_allocation(allocation_id, messages);
+
+        let timestamp_ns = receipts
+            .iter()
+            .map(|receipt| receipt.timestamp_ns)
+            .max()
+            .unwrap_or(0);
+        let value_aggregate = receipts.iter().map(|receipt| receipt.value).sum();
+
+        Self {
+            allocation_id,
+            timestamp_ns,
+            value_aggregate,
+            merkle_tree: MerkleTree::new(&receipts),
+        }
+    }
+
+    /// Builds an inclusion proof for `receipt`, or `None` if it is not among
+    /// the receipts this builder aggregates (including a receipt passed to
+    /// [RavBuilder::new] but excluded for a different `allocation_id`).
+    pub fn inclusion_proof(&self, receipt: &SignedReceipt) -> Option<MerkleProof> {
+        self.merkle_tree.inclusion_proof(&receipt.message)
+    }
+
+    /// Finalizes the RAV, embedding the Merkle root over the receipts this
+    /// builder aggregates.
+    pub fn build(self) -> ReceiptAggregateVoucher {
+        ReceiptAggregateVoucher {
+            allocation_id: self.allocation_id,
+            timestamp_ns: self.timestamp_ns,
+            value_aggregate: self.value_aggregate,
+            receipts_merkle_root: Some(self.merkle_tree.root()),
+        }
+    }
+}
+
+/// Filters `receipts` down to those matching `allocation_id`, split out from
+/// [RavBuilder::new] so the invariant can be tested without needing a full
+/// `SignedReceipt`.
+fn receipts_for_allocation(
+    allocation_id: Address,
+    receipts: Vec<crate::tap_receipt::Receipt>,
+) -> Vec<crate::tap_receipt::Receipt> {
+    receipts
+        .into_iter()
+        .filter(|receipt| receipt.allocation_id == allocation_id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::*;
+
+    use super::*;
+    use crate::tap_receipt::Receipt;
+
+    #[rstest]
+    fn excludes_receipts_for_a_different_allocation() {
+        let allocation_id =
+            Address::from_str("0xabababababababababababababababababababab").unwrap();
+        let other_allocation_id =
+            Address::from_str("0xdeaddeaddeaddeaddeaddeaddeaddeaddeaddead").unwrap();
+
+        let matching = Receipt::new(allocation_id, 100).unwrap();
+        let mismatched = Receipt::new(other_allocation_id, 999).unwrap();
+
+        let filtered = receipts_for_allocation(allocation_id, vec![matching.clone(), mismatched]);
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].value, matching.value);
+    }
+
+    #[rstest]
+    fn keeps_every_receipt_when_all_match() {
+        let allocation_id =
+            Address::from_str("0xabababababababababababababababababababab").unwrap();
+        let first = Receipt::new(allocation_id, 100).unwrap();
+        let second = Receipt::new(allocation_id, 200).unwrap();
+
+        let filtered = receipts_for_allocation(allocation_id, vec![first, second]);
+
+        assert_eq!(filtered.len(), 2);
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright 2023-, Semiotic AI, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Tap Manager
+//!
+//! [`TapManager`] ties the [`crate::checks`] pipeline together with the
+//! adapters that back the rest of a receipt's lifecycle: escrow reservation
+//! and persistence of whatever gets rejected along the way, so that failed
+//! receipts and RAV requests are always accounted for instead of silently
+//! dropped.
+
+mod rav_request;
+
+pub use crate::tap_receipt::SignedReceipt;
+pub use rav_request::RAVRequest;
+
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    adapters::{escrow_adapter::EscrowAdapter, failed_receipt_store_adapter::FailedReceiptStore},
+    checks::Checks,
+    receipt::{
+        state::{AwaitingReserve, Checking, Reserved},
+        ReceiptError, ReceiptWithState,
+    },
+    receipt_aggregate_voucher::ReceiptAggregateVoucher,
+};
+
+/// The outcome of a rejected [`check_receipt`](TapManager::check_receipt)
+/// or [`reserve_receipt`](TapManager::reserve_receipt) call: the receipt
+/// was rejected, and possibly also failed to persist.
+///
+/// Persistence failures are surfaced here rather than logged from within
+/// the library, so the caller decides how (and whether) to report them.
+#[derive(Debug, Error)]
+pub enum CheckReceiptError<A: std::error::Error + Send + Sync + 'static> {
+    /// The receipt was rejected and the rejection was persisted via
+    /// [`FailedReceiptStore::store_invalid_receipt`].
+    #[error("receipt rejected: {0}")]
+    Rejected(#[source] ReceiptError),
+
+    /// The receipt was rejected, and persisting that rejection via
+    /// [`FailedReceiptStore::store_invalid_receipt`] also failed. Both
+    /// errors are retained so the caller can decide whether to retry the
+    /// persistence, alert, or fall back to another mechanism.
+    #[error("receipt rejected ({rejection}) and the rejection could not be persisted: {store_error}")]
+    NotPersisted {
+        rejection: ReceiptError,
+        #[source]
+        store_error: A,
+    },
+}
+
+/// Ties the [`Checks`] pipeline together with the adapters that back the
+/// rest of a receipt's lifecycle: [`FailedReceiptStore`] persists whatever
+/// the pipeline rejects, and [`EscrowAdapter`] reserves collateral for
+/// whatever it accepts.
+pub struct TapManager<E, F> {
+    checks: Checks,
+    escrow_adapter: Arc<E>,
+    failed_receipt_store: Arc<F>,
+}
+
+impl<E, F> TapManager<E, F>
+where
+    E: EscrowAdapter,
+    F: FailedReceiptStore,
+{
+    /// Creates a new manager running `checks` against incoming receipts,
+    /// reserving escrow for accepted receipts via `escrow_adapter`, and
+    /// persisting whatever either step rejects via `failed_receipt_store`.
+    pub fn new(checks: Checks, escrow_adapter: Arc<E>, failed_receipt_store: Arc<F>) -> Self {
+        Self {
+            checks,
+            escrow_adapter,
+            failed_receipt_store,
+        }
+    }
+
+    /// Runs `receipt` through the check pipeline.
+    ///
+    /// On success, returns the receipt in the [`AwaitingReserve`] state. On
+    /// failure, persists the rejection via [`FailedReceiptStore`] and
+    /// returns [`CheckReceiptError::Rejected`]; if persisting the rejection
+    /// itself fails, returns [`CheckReceiptError::NotPersisted`] carrying
+    /// both errors instead of discarding the persistence failure.
+    pub async fn check_receipt(
+        &self,
+        receipt: ReceiptWithState<Checking>,
+    ) -> Result<ReceiptWithState<AwaitingReserve>, CheckReceiptError<F::AdapterError>> {
+        match self.checks.check(receipt).await {
+            Ok(awaiting_reserve) => Ok(awaiting_reserve),
+            Err(failed) => {
+                let rejection = failed.error.clone();
+                match self.failed_receipt_store.store_invalid_receipt(failed).await {
+                    Ok(()) => Err(CheckReceiptError::Rejected(rejection)),
+                    Err(store_error) => Err(CheckReceiptError::NotPersisted {
+                        rejection,
+                        store_error,
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Reserves escrow for a receipt that has passed the check pipeline.
+    ///
+    /// On success, returns the receipt in the [`Reserved`] state. On
+    /// failure (insufficient escrow, or a signature that no longer
+    /// recovers), persists the rejection via [`FailedReceiptStore`] and
+    /// returns [`CheckReceiptError::Rejected`]; if persisting the rejection
+    /// itself fails, returns [`CheckReceiptError::NotPersisted`].
+    pub async fn reserve_receipt(
+        &self,
+        receipt: ReceiptWithState<AwaitingReserve>,
+    ) -> Result<ReceiptWithState<Reserved>, CheckReceiptError<F::AdapterError>> {
+        let sender_id = match receipt.signed_receipt().recover_signer() {
+            Ok(sender_id) => sender_id,
+            Err(error) => return self.fail_awaiting_reserve(receipt, error).await,
+        };
+        let value = receipt.signed_receipt().message.value;
+
+        match self.escrow_adapter.reserve(sender_id, value).await {
+            Ok(()) => Ok(receipt.into_reserved()),
+            Err(_adapter_error) => {
+                let error = ReceiptError::InsufficientEscrow { sender_id, value };
+                self.fail_awaiting_reserve(receipt, error).await
+            }
+        }
+    }
+
+    async fn fail_awaiting_reserve(
+        &self,
+        receipt: ReceiptWithState<AwaitingReserve>,
+        error: ReceiptError,
+    ) -> Result<ReceiptWithState<Reserved>, CheckReceiptError<F::AdapterError>> {
+        let reason = error.to_string();
+        let failed = receipt.into_failed(error.clone(), "EscrowAdapter::reserve", reason);
+        match self.failed_receipt_store.store_invalid_receipt(failed).await {
+            Ok(()) => Err(CheckReceiptError::Rejected(error)),
+            Err(store_error) => Err(CheckReceiptError::NotPersisted {
+                rejection: error,
+                store_error,
+            }),
+        }
+    }
+
+    /// Persists a RAV request that the aggregator rejected.
+    pub async fn record_rejected_rav_request(
+        &self,
+        expected_rav: ReceiptAggregateVoucher,
+        response: String,
+        reason: String,
+    ) -> Result<(), F::AdapterError> {
+        self.failed_receipt_store
+            .store_failed_rav_request(expected_rav, response, reason)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use rstest::*;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct MockStoreError;
+
+    impl fmt::Display for MockStoreError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "mock store unavailable")
+        }
+    }
+
+    impl std::error::Error for MockStoreError {}
+
+    #[rstest]
+    fn rejected_reports_only_the_rejection_reason() {
+        let error: CheckReceiptError<MockStoreError> =
+            CheckReceiptError::Rejected(ReceiptError::NonUniqueReceipt);
+        assert_eq!(error.to_string(), "receipt rejected: Receipt is not unique");
+    }
+
+    #[rstest]
+    fn not_persisted_reports_both_the_rejection_and_the_store_error() {
+        let error = CheckReceiptError::NotPersisted {
+            rejection: ReceiptError::NonUniqueReceipt,
+            store_error: MockStoreError,
+        };
+        let message = error.to_string();
+        assert!(message.contains("Receipt is not unique"));
+        assert!(message.contains("mock store unavailable"));
+    }
+
+    #[rstest]
+    fn rejected_reports_insufficient_escrow_by_sender_and_value() {
+        use std::str::FromStr;
+
+        use alloy_primitives::Address;
+
+        let sender_id =
+            Address::from_str("0xabababababababababababababababababababab").unwrap();
+        let error: CheckReceiptError<MockStoreError> =
+            CheckReceiptError::Rejected(ReceiptError::InsufficientEscrow {
+                sender_id,
+                value: 1234,
+            });
+        let message = error.to_string();
+        assert!(message.contains(&sender_id.to_string()));
+        assert!(message.contains("1234"));
+    }
+}
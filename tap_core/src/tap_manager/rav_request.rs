@@ -1,15 +1,41 @@
 // Copyright 2023-, Semiotic AI, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use alloy_primitives::Address;
 use serde::{Deserialize, Serialize};
 
 use super::SignedReceipt;
-use crate::receipt_aggregate_voucher::ReceiptAggregateVoucher;
+use crate::{
+    receipt::{state::Failed, ReceiptWithState},
+    receipt_aggregate_voucher::{RavBuilder, ReceiptAggregateVoucher},
+};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 
 pub struct RAVRequest {
     pub valid_receipts: Vec<SignedReceipt>,
-    pub invalid_receipts: Vec<SignedReceipt>,
+    /// Receipts that failed the check pipeline, retained with the failing
+    /// check's identity and a human-readable reason so they can be
+    /// persisted via [crate::adapters::failed_receipt_store_adapter] rather
+    /// than dropped.
+    pub invalid_receipts: Vec<ReceiptWithState<Failed>>,
     pub expected_rav: ReceiptAggregateVoucher,
 }
+
+impl RAVRequest {
+    /// Builds a `RAVRequest` for `allocation_id`, computing `expected_rav`
+    /// (including its Merkle commitment over `valid_receipts`) via
+    /// [RavBuilder].
+    pub fn new(
+        allocation_id: Address,
+        valid_receipts: Vec<SignedReceipt>,
+        invalid_receipts: Vec<ReceiptWithState<Failed>>,
+    ) -> Self {
+        let expected_rav = RavBuilder::new(allocation_id, &valid_receipts).build();
+        Self {
+            valid_receipts,
+            invalid_receipts,
+            expected_rav,
+        }
+    }
+}